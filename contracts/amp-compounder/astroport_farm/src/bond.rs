@@ -5,6 +5,7 @@ use cosmwasm_std::{
 };
 
 use crate::error::ContractError;
+use crate::reward::settle_reward;
 use crate::state::{Config, ScalingOperation, CONFIG, STATE};
 
 use cw20::Expiration;
@@ -113,7 +114,7 @@ pub fn bond(
 
 /// Internal bond function used by bond and bond_to
 fn bond_internal(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     config: Config,
     staker_addr: Addr,
@@ -129,7 +130,12 @@ fn bond_internal(
 
     let mut state = STATE.load(deps.storage)?;
 
-    //TODO: withdraw reward to pending reward; before changing share
+    // settle the staker's outstanding reward at their pre-bond share before the
+    // share calculation below changes it, so newly-accrued rewards aren't
+    // redistributed across all bonders.
+    let prev_user_share =
+        query_token_balance(&deps.querier, &state.amp_lp_token.0, &staker_addr)?;
+    settle_reward(deps.branch(), &env, &mut state, &staker_addr, prev_user_share)?;
 
     // calculate share
     let bond_share = state.calc_bond_share(amount, lp_balance, ScalingOperation::Truncate);
@@ -149,7 +155,7 @@ fn bond_internal(
 /// ## Description
 /// Unbond LP token of sender
 pub fn unbond(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     sender_addr: String,
@@ -172,6 +178,16 @@ pub fn unbond(
         &env.contract.address,
     )?;
 
+    // settle the staker's outstanding reward at their pre-unbond share. unbond is only
+    // ever reached via the Cw20HookMsg::Unbond receive hook, and by the time that hook
+    // runs, the triggering cw20 Send has already debited `amount` from the staker's
+    // amp_lp_token balance, so the current balance understates their pre-unbond share
+    // by exactly `amount` - add it back to avoid forfeiting the reward on the unbonded
+    // share itself (a full-balance unbond would otherwise settle against a zero share).
+    let prev_user_share =
+        query_token_balance(&deps.querier, &state.amp_lp_token.0, &staker_addr)? + amount;
+    settle_reward(deps.branch(), &env, &mut state, &staker_addr, prev_user_share)?;
+
     let bond_amount = state.calc_bond_amount(lp_balance, amount);
     state.total_bond_share = state.total_bond_share.checked_sub(amount)?;
 
@@ -0,0 +1,135 @@
+use crate::bond::{bond, bond_assets, bond_to, unbond};
+use crate::error::ContractError;
+use crate::queries::{query_config, query_reward, query_state};
+use crate::reward::withdraw;
+use crate::state::{CONFIG, OWNERSHIP_PROPOSAL};
+
+use astroport::common::{claim_ownership, drop_ownership_proposal, propose_new_owner};
+use cosmwasm_std::{
+    entry_point, from_binary, to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response,
+    StdError, StdResult,
+};
+use cw20::Cw20ReceiveMsg;
+use eris::astroport_farm::{CallbackMsg, Cw20HookMsg, ExecuteMsg, MigrateMsg, QueryMsg};
+
+/// ## Description
+/// Exposes execute functions available in the contract.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::BondAssets {
+            assets,
+            minimum_receive,
+            no_swap,
+            slippage_tolerance,
+        } => bond_assets(deps, env, info, assets, minimum_receive, no_swap, slippage_tolerance),
+
+        ExecuteMsg::Receive(cw20_msg) => receive_cw20(deps, env, info, cw20_msg),
+
+        ExecuteMsg::Withdraw {} => withdraw(deps, env, info),
+
+        ExecuteMsg::UpdateConfig {
+            compound_proxy,
+        } => {
+            let mut config = CONFIG.load(deps.storage)?;
+            if info.sender != config.owner {
+                return Err(ContractError::Unauthorized {});
+            }
+
+            if let Some(compound_proxy) = compound_proxy {
+                config.compound_proxy = eris::adapters::compound_proxy::CompoundProxy(
+                    deps.api.addr_validate(&compound_proxy)?,
+                );
+            }
+
+            CONFIG.save(deps.storage, &config)?;
+            Ok(Response::new().add_attribute("action", "update_config"))
+        },
+
+        ExecuteMsg::ProposeNewOwner {
+            owner,
+            expires_in,
+        } => {
+            let config = CONFIG.load(deps.storage)?;
+
+            propose_new_owner(deps, info, env, owner, expires_in, config.owner, OWNERSHIP_PROPOSAL)
+                .map_err(|e| e.into())
+        },
+        ExecuteMsg::DropOwnershipProposal {} => {
+            let config = CONFIG.load(deps.storage)?;
+
+            drop_ownership_proposal(deps, info, config.owner, OWNERSHIP_PROPOSAL)
+                .map_err(|e| e.into())
+        },
+        ExecuteMsg::ClaimOwnership {} => {
+            claim_ownership(deps, info, env, OWNERSHIP_PROPOSAL, |deps, new_owner| {
+                CONFIG.update::<_, StdError>(deps.storage, |mut config| {
+                    config.owner = new_owner;
+                    Ok(config)
+                })?;
+
+                Ok(())
+            })
+            .map_err(|e| e.into())
+        },
+        ExecuteMsg::Callback(msg) => handle_callback(deps, env, info, msg),
+    }
+}
+
+fn receive_cw20(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    cw20_msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    match from_binary(&cw20_msg.msg)? {
+        Cw20HookMsg::Bond {} => bond(deps, env, info, cw20_msg.sender, cw20_msg.amount),
+        Cw20HookMsg::Unbond {} => unbond(deps, env, info, cw20_msg.sender, cw20_msg.amount),
+    }
+}
+
+/// ## Description
+/// Handles internal callbacks. Only callable by the contract itself.
+fn handle_callback(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: CallbackMsg,
+) -> Result<Response, ContractError> {
+    if env.contract.address != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    match msg {
+        CallbackMsg::BondTo {
+            to,
+            prev_balance,
+            minimum_receive,
+        } => bond_to(deps, env, info, to, prev_balance, minimum_receive),
+    }
+}
+
+/// ## Description
+/// Exposes all the queries available in the contract.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&query_config(deps)?),
+        QueryMsg::State {} => to_binary(&query_state(deps, env)?),
+        QueryMsg::Reward {
+            address,
+        } => to_binary(&query_reward(deps, env, address)?),
+    }
+}
+
+/// ## Description
+/// Used for migration of contract. Returns the default object of type [`Response`].
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> StdResult<Response> {
+    Ok(Response::default())
+}
@@ -0,0 +1,24 @@
+use cosmwasm_std::{OverflowError, StdError};
+use thiserror::Error;
+
+/// This enum describes contract errors.
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Assertion failed; minimum receive amount: {minimum_receive}, actual amount: {amount}")]
+    AssertionMinimumReceive {
+        minimum_receive: cosmwasm_std::Uint128,
+        amount: cosmwasm_std::Uint128,
+    },
+
+    #[error("Nothing to claim")]
+    NothingToClaim {},
+}
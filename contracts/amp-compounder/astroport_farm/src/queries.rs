@@ -0,0 +1,73 @@
+use astroport::generator::{PendingTokenResponse, QueryMsg as GeneratorQueryMsg};
+use astroport::querier::query_token_balance;
+use cosmwasm_std::{Decimal, Deps, Env, StdResult, Uint128};
+
+use eris::astroport_farm::{ConfigResponse, RewardResponse, StateResponse};
+
+use crate::state::{CONFIG, STATE, USER_REWARD};
+
+/// ## Description
+/// Returns information about the farm config.
+pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
+    let config = CONFIG.load(deps.storage)?;
+
+    Ok(ConfigResponse {
+        owner: config.owner.to_string(),
+        lp_token: config.lp_token.to_string(),
+        compound_proxy: config.compound_proxy.0.to_string(),
+        staking_contract: config.staking_contract.0.to_string(),
+    })
+}
+
+/// ## Description
+/// Returns the current bond state.
+pub fn query_state(deps: Deps, env: Env) -> StdResult<StateResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let state = STATE.load(deps.storage)?;
+
+    let total_lp_amount = config.staking_contract.query_deposit(
+        &deps.querier,
+        &config.lp_token,
+        &env.contract.address,
+    )?;
+
+    Ok(StateResponse {
+        total_bond_share: state.total_bond_share,
+        total_lp_amount,
+    })
+}
+
+/// ## Description
+/// Returns a staker's currently claimable pending reward, including the portion that
+/// has accrued since their last checkpoint but has not yet been settled in storage.
+pub fn query_reward(deps: Deps, env: Env, address: String) -> StdResult<RewardResponse> {
+    let staker_addr = deps.api.addr_validate(&address)?;
+    let config = CONFIG.load(deps.storage)?;
+    let mut state = STATE.load(deps.storage)?;
+
+    let pending: PendingTokenResponse = deps.querier.query_wasm_smart(
+        config.staking_contract.0.to_string(),
+        &GeneratorQueryMsg::PendingToken {
+            lp_token: config.lp_token.to_string(),
+            user: env.contract.address.to_string(),
+        },
+    )?;
+
+    if !state.total_bond_share.is_zero() {
+        state.reward_index += Decimal::from_ratio(pending.pending, state.total_bond_share);
+    }
+
+    let user_share = query_token_balance(&deps.querier, &state.amp_lp_token.0, &staker_addr)?;
+    let user_reward = USER_REWARD.may_load(deps.storage, &staker_addr)?;
+
+    let pending = match user_reward {
+        Some(user_reward) => {
+            user_reward.pending + user_share * (state.reward_index - user_reward.index)
+        },
+        None => Uint128::zero(),
+    };
+
+    Ok(RewardResponse {
+        pending,
+    })
+}
@@ -0,0 +1,88 @@
+use astroport::generator::{PendingTokenResponse, QueryMsg as GeneratorQueryMsg};
+use astroport::querier::query_token_balance;
+use cosmwasm_std::{attr, Addr, Decimal, DepsMut, Env, MessageInfo, Response, Uint128};
+
+use eris::adapters::asset::AssetEx;
+
+use crate::error::ContractError;
+use crate::state::{State, UserReward, CONFIG, STATE, USER_REWARD};
+
+/// ## Description
+/// Folds any reward accrued since the last checkpoint into the global `reward_index`,
+/// then settles `staker_addr`'s pending reward at their pre-mutation `user_share`.
+/// Must be called before `total_bond_share` or the user's amp LP balance is changed,
+/// so that newly-accrued rewards are never redistributed across other bonders.
+pub fn settle_reward(
+    deps: DepsMut,
+    env: &Env,
+    state: &mut State,
+    staker_addr: &Addr,
+    user_share: Uint128,
+) -> Result<(), ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let pending: PendingTokenResponse = deps.querier.query_wasm_smart(
+        config.staking_contract.0.to_string(),
+        &GeneratorQueryMsg::PendingToken {
+            lp_token: config.lp_token.to_string(),
+            user: env.contract.address.to_string(),
+        },
+    )?;
+
+    // if nobody is bonded yet there is no share to attribute new rewards to;
+    // carry them forward rather than advancing the index against a zero denominator.
+    if !state.total_bond_share.is_zero() {
+        state.reward_index += Decimal::from_ratio(pending.pending, state.total_bond_share);
+    }
+
+    // a staker's first interaction starts at the current index, so they don't
+    // retroactively earn rewards that accrued before they bonded.
+    let mut user_reward = USER_REWARD.may_load(deps.storage, staker_addr)?.unwrap_or(UserReward {
+        index: state.reward_index,
+        pending: Uint128::zero(),
+    });
+
+    user_reward.pending += user_share * (state.reward_index - user_reward.index);
+    user_reward.index = state.reward_index;
+
+    USER_REWARD.save(deps.storage, staker_addr, &user_reward)?;
+
+    Ok(())
+}
+
+/// ## Description
+/// Claims the sender's accrued pending reward and transfers it out.
+pub fn withdraw(mut deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let mut state = STATE.load(deps.storage)?;
+    let user_share = query_token_balance(&deps.querier, &state.amp_lp_token.0, &info.sender)?;
+
+    settle_reward(deps.branch(), &env, &mut state, &info.sender, user_share)?;
+    STATE.save(deps.storage, &state)?;
+
+    let mut user_reward = USER_REWARD.load(deps.storage, &info.sender)?;
+    let pending = user_reward.pending;
+    if pending.is_zero() {
+        return Err(ContractError::NothingToClaim {});
+    }
+
+    user_reward.pending = Uint128::zero();
+    USER_REWARD.save(deps.storage, &info.sender, &user_reward)?;
+
+    let config = CONFIG.load(deps.storage)?;
+
+    Ok(Response::new()
+        .add_messages(vec![
+            // `settle_reward` only folds the generator's reported pending reward into
+            // bookkeeping - the tokens themselves don't move until the generator is
+            // actually asked to pay out. A zero-amount withdraw claims the outstanding
+            // reward without touching the bonded LP balance, so a standalone `Withdraw`
+            // doesn't have to piggyback on a bond/unbond to pull funds into the contract.
+            config.staking_contract.withdraw_msg(config.lp_token.to_string(), Uint128::zero())?,
+            config.base_reward_token.with_balance(pending).transfer_msg(&info.sender)?,
+        ])
+        .add_attributes(vec![
+            attr("action", "withdraw"),
+            attr("staker_addr", info.sender),
+            attr("amount", pending),
+        ]))
+}
@@ -0,0 +1,100 @@
+use astroport::asset::AssetInfo;
+use astroport::common::OwnershipProposal;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Uint128, Uint256};
+use cw_storage_plus::{Item, Map};
+
+use eris::adapters::amp_lp_token::AmpLpToken;
+use eris::adapters::compound_proxy::CompoundProxy;
+use eris::adapters::generator::Generator;
+
+/// This structure stores the main parameters for the contract.
+#[cw_serde]
+pub struct Config {
+    /// Address that's allowed to update config
+    pub owner: Addr,
+    /// The LP token that can be bonded
+    pub lp_token: Addr,
+    /// The compound proxy contract used to turn rewards back into `lp_token`
+    pub compound_proxy: CompoundProxy,
+    /// The generator/staking contract that `lp_token` is deposited into
+    pub staking_contract: Generator,
+    /// The asset that the staking contract pays out as a reward
+    pub base_reward_token: AssetInfo,
+}
+
+/// Determines whether a bond share calculation rounds down or up.
+#[cw_serde]
+pub enum ScalingOperation {
+    Truncate,
+    Ceil,
+}
+
+/// This structure stores the runtime state for the contract.
+#[cw_serde]
+pub struct State {
+    /// The amp LP (staking derivative) token minted to bonders
+    pub amp_lp_token: AmpLpToken,
+    /// Total amount of `amp_lp_token` shares that have been minted
+    pub total_bond_share: Uint128,
+    /// Lifetime reward accrued per bonded share, used to settle per-user rewards
+    pub reward_index: Decimal,
+}
+
+impl State {
+    /// Converts a deposited `lp_token` amount into a number of bond shares.
+    pub fn calc_bond_share(
+        &self,
+        amount: Uint128,
+        lp_balance: Uint128,
+        op: ScalingOperation,
+    ) -> Uint128 {
+        if self.total_bond_share.is_zero() || lp_balance.is_zero() {
+            amount
+        } else {
+            match op {
+                ScalingOperation::Truncate => {
+                    amount.multiply_ratio(self.total_bond_share, lp_balance)
+                },
+                ScalingOperation::Ceil => {
+                    let share = amount.multiply_ratio(self.total_bond_share, lp_balance);
+                    let remainder =
+                        amount.full_mul(self.total_bond_share) % Uint256::from(lp_balance);
+                    if remainder.is_zero() {
+                        share
+                    } else {
+                        share + Uint128::new(1)
+                    }
+                },
+            }
+        }
+    }
+
+    /// Converts a number of bond shares into the underlying `lp_token` amount.
+    pub fn calc_bond_amount(&self, lp_balance: Uint128, bond_share: Uint128) -> Uint128 {
+        if self.total_bond_share.is_zero() {
+            Uint128::zero()
+        } else {
+            lp_balance.multiply_ratio(bond_share, self.total_bond_share)
+        }
+    }
+}
+
+/// The per-staker reward checkpoint used to settle pending rewards lazily.
+#[cw_serde]
+#[derive(Default)]
+pub struct UserReward {
+    /// The global `reward_index` the last time this user's rewards were settled
+    pub index: Decimal,
+    /// Rewards that have been settled but not yet claimed via `Withdraw {}`
+    pub pending: Uint128,
+}
+
+/// Stores the contract config.
+pub const CONFIG: Item<Config> = Item::new("config");
+/// Stores the contract runtime state.
+pub const STATE: Item<State> = Item::new("state");
+/// Stores each staker's reward checkpoint by staker address.
+pub const USER_REWARD: Map<&Addr, UserReward> = Map::new("user_reward");
+/// Contains a proposal to change contract ownership
+pub const OWNERSHIP_PROPOSAL: Item<OwnershipProposal> = Item::new("ownership_proposal");
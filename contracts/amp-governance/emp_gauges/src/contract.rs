@@ -0,0 +1,105 @@
+use crate::error::ContractError;
+use crate::execute::{tune_emps, update_config, update_fixed_emps, vote};
+use crate::queries::{query_config, query_tune_info, query_validator_info};
+use crate::state::{Config, CONFIG, OWNERSHIP_PROPOSAL};
+
+use astroport::common::{claim_ownership, drop_ownership_proposal, propose_new_owner};
+use cosmwasm_std::{
+    entry_point, to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult,
+};
+use eris::emp_gauges::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+
+/// ## Description
+/// Creates a new contract with the specified parameters in the [`InstantiateMsg`].
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let config = Config {
+        owner: deps.api.addr_validate(&msg.owner)?,
+        validators_limit: msg.validators_limit,
+    };
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new())
+}
+
+/// ## Description
+/// Exposes execute functions available in the contract.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Vote {
+            validator,
+            amount,
+            lock_end_period,
+        } => vote(deps, env, info, validator, amount, lock_end_period),
+
+        ExecuteMsg::UpdateFixedEmps {
+            validator,
+            amount,
+        } => update_fixed_emps(deps, env, info, validator, amount),
+
+        ExecuteMsg::TuneEmps {} => tune_emps(deps, env, info),
+
+        ExecuteMsg::UpdateConfig {
+            validators_limit,
+        } => update_config(deps, info, validators_limit),
+
+        ExecuteMsg::ProposeNewOwner {
+            owner,
+            expires_in,
+        } => {
+            let config = CONFIG.load(deps.storage)?;
+
+            propose_new_owner(deps, info, env, owner, expires_in, config.owner, OWNERSHIP_PROPOSAL)
+                .map_err(|e| e.into())
+        },
+        ExecuteMsg::DropOwnershipProposal {} => {
+            let config = CONFIG.load(deps.storage)?;
+
+            drop_ownership_proposal(deps, info, config.owner, OWNERSHIP_PROPOSAL)
+                .map_err(|e| e.into())
+        },
+        ExecuteMsg::ClaimOwnership {} => {
+            claim_ownership(deps, info, env, OWNERSHIP_PROPOSAL, |deps, new_owner| {
+                CONFIG.update::<_, StdError>(deps.storage, |mut config| {
+                    config.owner = new_owner;
+                    Ok(config)
+                })?;
+
+                Ok(())
+            })
+            .map_err(|e| e.into())
+        },
+    }
+}
+
+/// ## Description
+/// Exposes all the queries available in the contract.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&query_config(deps)?),
+        QueryMsg::ValidatorInfo {
+            validator,
+        } => to_binary(&query_validator_info(deps, env, validator)?),
+        QueryMsg::TuneInfo {} => to_binary(&query_tune_info(deps)?),
+    }
+}
+
+/// ## Description
+/// Used for migration of contract. Returns the default object of type [`Response`].
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> StdResult<Response> {
+    Ok(Response::default())
+}
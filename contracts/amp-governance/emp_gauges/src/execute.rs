@@ -0,0 +1,142 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Order, Response, Uint128};
+
+use eris::emp_gauges::GaugeInfoResponse;
+
+use crate::error::ContractError;
+use crate::state::{Config, CONFIG, TUNE_INFO, VALIDATORS, VALIDATOR_PERIODS, VALIDATOR_SLOPE_CHANGES, VALIDATOR_VOTES};
+use crate::utils::{get_fixed_emp, get_period, get_validator_info, update_validator_info_to_period};
+
+/// ## Description
+/// Casts a vote for `validator`, locking `amount` of voting power until `lock_end_period`.
+/// The vote's weight decays linearly to zero at `lock_end_period`, recorded as
+/// `slope = amount / lock_duration` (rounded down) plus `bias = slope * lock_duration`,
+/// so any remainder of `amount` that the lock duration does not divide is dropped.
+pub fn vote(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    validator: String,
+    amount: Uint128,
+    lock_end_period: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let validator_addr = deps.api.addr_validate(&validator)?;
+    let period = get_period(env.block.time.seconds());
+
+    if lock_end_period <= period {
+        return Err(ContractError::LockExpired {});
+    }
+
+    let lock_duration = lock_end_period - period;
+    let slope = Uint128::new(amount.u128() / lock_duration as u128);
+    let bias = slope * Uint128::from(lock_duration);
+
+    let mut voted = get_validator_info(deps.as_ref(), &validator_addr, period)?;
+    voted.voting_power += bias;
+    voted.slope += slope;
+
+    VALIDATOR_VOTES.save(deps.storage, (period, &validator_addr), &voted)?;
+    VALIDATOR_PERIODS.save(deps.storage, (&validator_addr, period), &())?;
+    VALIDATORS.save(deps.storage, &validator_addr, &())?;
+
+    VALIDATOR_SLOPE_CHANGES.update(
+        deps.storage,
+        (&validator_addr, lock_end_period),
+        |existing| -> Result<_, ContractError> { Ok(existing.unwrap_or_default() + slope) },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "vote")
+        .add_attribute("validator", validator_addr)
+        .add_attribute("amount", amount)
+        .add_attribute("slope", slope))
+}
+
+/// ## Description
+/// Sets `validator`'s non-decaying fixed emp weight, in effect from the current period onward.
+pub fn update_fixed_emps(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    validator: String,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let validator_addr = deps.api.addr_validate(&validator)?;
+    let period = get_period(env.block.time.seconds());
+
+    crate::state::VALIDATOR_FIXED_EMPS.save(deps.storage, (&validator_addr, period), &amount)?;
+    VALIDATORS.save(deps.storage, &validator_addr, &())?;
+
+    Ok(Response::new().add_attribute("action", "update_fixed_emps").add_attribute("validator", validator_addr))
+}
+
+/// ## Description
+/// Recomputes every known validator's tuned emission weight up to the current period,
+/// replaying slope changes period-by-period so expired locks stop contributing, and
+/// adding each validator's non-decaying fixed emp weight on top.
+pub fn tune_emps(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let period = get_period(env.block.time.seconds());
+
+    let validators = VALIDATORS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<cosmwasm_std::StdResult<Vec<_>>>()?;
+
+    let mut weights = vec![];
+    for validator in validators {
+        let voted = update_validator_info_to_period(deps.branch(), &validator, period)?;
+        let fixed = get_fixed_emp(deps.storage, &validator, period)?;
+
+        let weight = voted.voting_power + fixed;
+        if !weight.is_zero() {
+            weights.push((validator.to_string(), weight));
+        }
+    }
+
+    weights.truncate(config.validators_limit as usize);
+
+    TUNE_INFO.save(
+        deps.storage,
+        &GaugeInfoResponse {
+            tune_period: period,
+            validators: weights,
+        },
+    )?;
+
+    Ok(Response::new().add_attribute("action", "tune_emps").add_attribute("period", period.to_string()))
+}
+
+/// ## Description
+/// Updates the contract config.
+pub fn update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    validators_limit: Option<u64>,
+) -> Result<Response, ContractError> {
+    let mut config: Config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if let Some(validators_limit) = validators_limit {
+        config.validators_limit = validators_limit;
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attribute("action", "update_config"))
+}
@@ -0,0 +1,28 @@
+use cosmwasm_std::{Deps, Env, StdResult};
+
+use eris::emp_gauges::{ConfigResponse, GaugeInfoResponse, VotedValidatorInfoResponse};
+
+use crate::state::{CONFIG, TUNE_INFO};
+use crate::utils::{get_period, get_validator_info};
+
+/// Returns information about the contract config.
+pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
+    CONFIG.load(deps.storage)
+}
+
+/// Returns `validator`'s voting power decayed to the current period.
+pub fn query_validator_info(
+    deps: Deps,
+    env: Env,
+    validator: String,
+) -> StdResult<VotedValidatorInfoResponse> {
+    let validator_addr = deps.api.addr_validate(&validator)?;
+    let period = get_period(env.block.time.seconds());
+
+    get_validator_info(deps, &validator_addr, period)
+}
+
+/// Returns the last tuning result.
+pub fn query_tune_info(deps: Deps) -> StdResult<GaugeInfoResponse> {
+    TUNE_INFO.load(deps.storage)
+}
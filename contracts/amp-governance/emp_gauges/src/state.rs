@@ -32,7 +32,9 @@ pub const VALIDATOR_PERIODS: Map<(&Addr, u64), ()> = Map::new("validator_periods
 /// Slope changes for a specific pool address by key ( pool_addr -> period ).
 pub const VALIDATOR_SLOPE_CHANGES: Map<(&Addr, u64), Uint128> = Map::new("validator_slope_changes");
 
-/// HashSet based on [`Map`]. It contains all pool addresses whose voting power > 0.
+/// Non-decaying emp weight set directly by the owner for a validator, in effect from
+/// the given period onward, by key ( pool_addr -> period ). Added on top of the
+/// decaying voting power computed from [`VALIDATOR_VOTES`] when tuning.
 pub const VALIDATOR_FIXED_EMPS: Map<(&Addr, u64), Uint128> = Map::new("validator_fixed_emps");
 
 // pub const EMP_ID: Item<u64> = Item::new("emp_id");
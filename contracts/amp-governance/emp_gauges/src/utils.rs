@@ -0,0 +1,112 @@
+use cosmwasm_std::{Addr, Deps, DepsMut, Order, StdResult, Storage, Uint128};
+use cw_storage_plus::Bound;
+
+use crate::state::{VotedValidatorInfo, VALIDATOR_PERIODS, VALIDATOR_SLOPE_CHANGES, VALIDATOR_VOTES};
+
+/// Length, in seconds, of one voting period.
+pub const PERIOD_SECONDS: u64 = 86400;
+
+/// Converts a block time into the voting period it falls into.
+pub fn get_period(timestamp: u64) -> u64 {
+    timestamp / PERIOD_SECONDS
+}
+
+/// Returns the most recent period at or before `period` for which `validator`
+/// has a checkpoint, if it has ever received a vote.
+fn last_checkpoint_period(
+    storage: &dyn Storage,
+    validator: &Addr,
+    period: u64,
+) -> StdResult<Option<u64>> {
+    VALIDATOR_PERIODS
+        .prefix(validator)
+        .range(storage, None, Some(Bound::inclusive(period)), Order::Descending)
+        .next()
+        .transpose()
+        .map(|item| item.map(|(period, _)| period))
+}
+
+/// Returns the non-decaying fixed emp weight in effect for `validator` at `period`
+/// (the most recently set value at or before `period`, or zero if none was ever set).
+pub fn get_fixed_emp(storage: &dyn Storage, validator: &Addr, period: u64) -> StdResult<Uint128> {
+    Ok(crate::state::VALIDATOR_FIXED_EMPS
+        .prefix(validator)
+        .range(storage, None, Some(Bound::inclusive(period)), Order::Descending)
+        .next()
+        .transpose()?
+        .map(|(_, amount)| amount)
+        .unwrap_or_default())
+}
+
+/// Replays `validator`'s history one period at a time, from `checkpoint_period`
+/// (exclusive) up to `period` (inclusive), applying every scheduled slope change
+/// exactly at the period it takes effect so a lock's slope stops decaying bias
+/// the moment it expires, rather than only at the end of the range. Bias and
+/// slope are clamped at zero rather than allowed to underflow.
+fn replay_validator_info(
+    storage: &dyn Storage,
+    validator: &Addr,
+    checkpoint_period: u64,
+    mut info: VotedValidatorInfo,
+    period: u64,
+) -> StdResult<VotedValidatorInfo> {
+    for p in (checkpoint_period + 1)..=period {
+        // decay bias by the slope still in effect up through period `p` *before*
+        // applying `p`'s own slope change, so a lock's final period of decay uses
+        // its pre-expiry slope rather than the (often zero) post-expiry one.
+        info.voting_power = info.voting_power.saturating_sub(info.slope);
+        let slope_change = VALIDATOR_SLOPE_CHANGES.may_load(storage, (validator, p))?.unwrap_or_default();
+        info.slope = info.slope.saturating_sub(slope_change);
+    }
+
+    Ok(info)
+}
+
+/// Returns `validator`'s decaying voting power as of `period`: finds the last
+/// checkpoint at or before `period`, then replays every period in between one
+/// at a time via [`replay_validator_info`] so a slope change that falls strictly
+/// before `period` stops decaying bias from the moment it takes effect, rather
+/// than over the whole elapsed range.
+pub fn get_validator_info(
+    deps: Deps,
+    validator: &Addr,
+    period: u64,
+) -> StdResult<VotedValidatorInfo> {
+    let checkpoint_period = match last_checkpoint_period(deps.storage, validator, period)? {
+        Some(checkpoint_period) => checkpoint_period,
+        None => return Ok(VotedValidatorInfo::default()),
+    };
+
+    let info = VALIDATOR_VOTES.load(deps.storage, (checkpoint_period, validator))?;
+    if checkpoint_period == period {
+        return Ok(info);
+    }
+
+    replay_validator_info(deps.storage, validator, checkpoint_period, info, period)
+}
+
+/// Replays `validator`'s history up to `period` via [`replay_validator_info`],
+/// then writes a fresh checkpoint at `period` so subsequent reads don't need to
+/// replay this history again.
+pub fn update_validator_info_to_period(
+    deps: DepsMut,
+    validator: &Addr,
+    period: u64,
+) -> StdResult<VotedValidatorInfo> {
+    let checkpoint_period = last_checkpoint_period(deps.storage, validator, period)?;
+
+    let info = match checkpoint_period {
+        Some(checkpoint_period) => VALIDATOR_VOTES.load(deps.storage, (checkpoint_period, validator))?,
+        None => return Ok(VotedValidatorInfo::default()),
+    };
+
+    let checkpoint_period = checkpoint_period.expect("checked above");
+    let info = replay_validator_info(deps.storage, validator, checkpoint_period, info, period)?;
+
+    if checkpoint_period != period {
+        VALIDATOR_VOTES.save(deps.storage, (period, validator), &info)?;
+        VALIDATOR_PERIODS.save(deps.storage, (validator, period), &())?;
+    }
+
+    Ok(info)
+}
@@ -0,0 +1,93 @@
+use astroport::asset::{Asset, AssetInfo, PairInfo};
+use astroport::factory::{FeeInfoResponse, PairType, QueryMsg as FactoryQueryMsg};
+use astroport::pair::{PoolResponse, QueryMsg as PairQueryMsg};
+use cosmwasm_std::{Addr, CosmosMsg, Decimal, StdResult, Uint128};
+
+use eris::adapters::factory::Factory;
+
+use crate::custom_query::CustomQueryType;
+
+type Deps<'a> = cosmwasm_std::Deps<'a, CustomQueryType>;
+
+/// Looks up the pair contract for `offer_info -> ask_info` directly on the factory,
+/// without going through any declared bridge hop.
+pub fn query_pair(
+    deps: Deps<'_>,
+    factory_contract: &Addr,
+    offer_info: &AssetInfo,
+    ask_info: &AssetInfo,
+) -> StdResult<PairInfo> {
+    Factory(factory_contract.clone())
+        .query_pair(&deps.querier, [offer_info.clone(), ask_info.clone()])
+}
+
+/// Builds the swap execute message for `offer_asset` against `pair_contract`.
+pub fn swap_msg(
+    pair_contract: &Addr,
+    offer_asset: Asset,
+    max_spread: Decimal,
+) -> StdResult<CosmosMsg> {
+    offer_asset.into_swap_msg(pair_contract.clone(), Some(max_spread), None)
+}
+
+/// Computes the output of swapping `offer` through `pair_contract` ourselves,
+/// via the constant-product formula applied to the pair's own reserves
+/// (`out = (y * dx) / (x + dx)`, with the pool's fee taken out of `dx` first).
+/// This is independent of whatever price the pair itself would report through
+/// its `Simulation` query, which is the point: it lets us catch a pair that's
+/// thin or manipulated rather than trusting its own numbers.
+///
+/// The trading fee isn't owned by the pair contract - it's a per-`pair_type`
+/// value owned by the factory, so it's fetched via `FeeInfo` on `factory_contract`
+/// rather than off the pair's own `Config`.
+pub fn expected_hop_output(
+    deps: Deps<'_>,
+    factory_contract: &Addr,
+    pair_type: &PairType,
+    pair_contract: &Addr,
+    offer: &Asset,
+) -> StdResult<Uint128> {
+    let pool: PoolResponse = deps.querier.query_wasm_smart(pair_contract, &PairQueryMsg::Pool {})?;
+    let fee_info: FeeInfoResponse = deps.querier.query_wasm_smart(
+        factory_contract,
+        &FactoryQueryMsg::FeeInfo {
+            pair_type: pair_type.clone(),
+        },
+    )?;
+
+    let (offer_reserve, ask_reserve) = if pool.assets[0].info == offer.info {
+        (pool.assets[0].amount, pool.assets[1].amount)
+    } else {
+        (pool.assets[1].amount, pool.assets[0].amount)
+    };
+
+    let dx_after_fee =
+        offer.amount.multiply_ratio(10_000u128 - fee_info.total_fee_bps as u128, 10_000u128);
+
+    Ok(ask_reserve.multiply_ratio(dx_after_fee, offer_reserve + dx_after_fee))
+}
+
+/// Chains [`expected_hop_output`] across every consecutive pair in `path`,
+/// multiplying the price impact of each hop to arrive at the expected
+/// end-to-end output of swapping `amount` of `path[0]` all the way to the
+/// last asset in `path`.
+pub fn expected_path_output(
+    deps: Deps<'_>,
+    factory_contract: &Addr,
+    path: &[AssetInfo],
+    amount: Uint128,
+) -> StdResult<Uint128> {
+    let mut current = amount;
+
+    for hop in path.windows(2) {
+        let pair = query_pair(deps, factory_contract, &hop[0], &hop[1])?;
+        let offer = Asset {
+            info: hop[0].clone(),
+            amount: current,
+        };
+        current =
+            expected_hop_output(deps, factory_contract, &pair.pair_type, &pair.contract_addr, &offer)?;
+    }
+
+    Ok(current)
+}
@@ -0,0 +1,157 @@
+use crate::custom_query::CustomQueryType;
+use crate::error::ContractError;
+use crate::execute::{collect, distribute_fees, swap_bridge_assets, update_bridges, update_config};
+use crate::queries::{
+    query_balances, query_bridges, query_config, query_route, query_simulate_collect,
+};
+use crate::state::{Config, CONFIG, OWNERSHIP_PROPOSAL};
+
+use astroport::common::{claim_ownership, drop_ownership_proposal, propose_new_owner};
+use cosmwasm_std::{
+    entry_point, to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult,
+};
+use eris::fees_collector::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+
+/// ## Description
+/// Creates a new contract with the specified parameters in the [`InstantiateMsg`].
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut<CustomQueryType>,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let config = Config {
+        owner: deps.api.addr_validate(&msg.owner)?,
+        operator: deps.api.addr_validate(&msg.operator)?,
+        factory_contract: deps.api.addr_validate(&msg.factory_contract)?,
+        stablecoin: msg.stablecoin,
+        target_list: msg
+            .target_list
+            .iter()
+            .map(|t| t.check(deps.api))
+            .collect::<StdResult<_>>()?,
+        max_spread: msg.max_spread.unwrap_or(cosmwasm_std::Decimal::percent(1)),
+        price_feed: msg.price_feed.map(|addr| deps.api.addr_validate(&addr)).transpose()?,
+    };
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new())
+}
+
+/// ## Description
+/// Exposes execute functions available in the contract.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut<CustomQueryType>,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Collect {
+            assets,
+        } => collect(deps, env, assets),
+
+        ExecuteMsg::SwapBridgeAssets {
+            assets,
+            depth,
+        } => {
+            if env.contract.address != info.sender {
+                return Err(ContractError::Unauthorized {});
+            }
+            swap_bridge_assets(deps, env, assets, depth)
+        },
+
+        ExecuteMsg::DistributeFees {} => {
+            if env.contract.address != info.sender {
+                return Err(ContractError::Unauthorized {});
+            }
+            distribute_fees(deps, env)
+        },
+
+        ExecuteMsg::UpdateBridges {
+            add,
+            remove,
+        } => {
+            let config = CONFIG.load(deps.storage)?;
+            if info.sender != config.owner && info.sender != config.operator {
+                return Err(ContractError::Unauthorized {});
+            }
+            update_bridges(deps, add, remove)
+        },
+
+        ExecuteMsg::UpdateConfig {
+            operator,
+            factory_contract,
+            target_list,
+            max_spread,
+            price_feed,
+        } => update_config(deps, info, operator, factory_contract, target_list, max_spread, price_feed),
+
+        ExecuteMsg::ProposeNewOwner {
+            owner,
+            expires_in,
+        } => {
+            let config = CONFIG.load(deps.storage)?;
+
+            propose_new_owner(deps, info, env, owner, expires_in, config.owner, OWNERSHIP_PROPOSAL)
+                .map_err(|e| e.into())
+        },
+        ExecuteMsg::DropOwnershipProposal {} => {
+            let config = CONFIG.load(deps.storage)?;
+
+            drop_ownership_proposal(deps, info, config.owner, OWNERSHIP_PROPOSAL)
+                .map_err(|e| e.into())
+        },
+        ExecuteMsg::ClaimOwnership {} => {
+            claim_ownership(deps, info, env, OWNERSHIP_PROPOSAL, |deps, new_owner| {
+                CONFIG.update::<_, StdError>(deps.storage, |mut config| {
+                    config.owner = new_owner;
+                    Ok(config)
+                })?;
+
+                Ok(())
+            })
+            .map_err(|e| e.into())
+        },
+    }
+}
+
+/// ## Description
+/// Exposes all the queries available in the contract.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps<CustomQueryType>, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&query_config(deps)?),
+        QueryMsg::Balances {
+            assets,
+        } => to_binary(&query_balances(deps, &env.contract.address, assets)?),
+        QueryMsg::Bridges {} => to_binary(&query_bridges(deps)?),
+        QueryMsg::Route {
+            from,
+        } => {
+            let route = query_route(deps, from).map_err(|e| StdError::generic_err(e.to_string()))?;
+            to_binary(&route)
+        },
+        QueryMsg::SimulateCollect {
+            assets,
+        } => {
+            let preview = query_simulate_collect(deps, &env, assets)
+                .map_err(|e| StdError::generic_err(e.to_string()))?;
+            to_binary(&preview)
+        },
+    }
+}
+
+/// ## Description
+/// Used for migration of contract. Returns the default object of type [`Response`].
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(
+    _deps: DepsMut<CustomQueryType>,
+    _env: Env,
+    _msg: MigrateMsg,
+) -> StdResult<Response> {
+    Ok(Response::default())
+}
@@ -0,0 +1,35 @@
+use cosmwasm_std::{OverflowError, StdError, Uint128};
+use thiserror::Error;
+
+/// This enum describes maker contract errors.
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Max bridge depth of {0} reached")]
+    MaxBridgeDepth(u64),
+
+    #[error("No swap route found from {0} to the stablecoin")]
+    NoRouteFound(String),
+
+    #[error("Spread assertion failed: received {actual} stablecoin, expected at least {minimum_received}")]
+    MaxSpreadAssertion {
+        actual: Uint128,
+        minimum_received: Uint128,
+    },
+
+    #[error(
+        "Price feed rejected swap: AMM-simulated output {amm_output} is below the reference minimum of {reference_minimum}"
+    )]
+    OracleRejected {
+        amm_output: Uint128,
+        reference_minimum: Uint128,
+    },
+}
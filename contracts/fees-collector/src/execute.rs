@@ -0,0 +1,329 @@
+use std::collections::HashMap;
+
+use astroport::asset::{Asset, AssetInfo};
+use cosmwasm_std::{to_binary, CosmosMsg, Decimal, Env, MessageInfo, Response, Uint128, WasmMsg};
+
+use eris::adapters::asset::AssetEx;
+use eris::fees_collector::{AssetWithLimit, ExecuteMsg, TargetConfigUnchecked};
+
+use crate::bridge::{expected_path_output, query_pair, swap_msg};
+use crate::custom_query::CustomQueryType;
+#[cfg(feature = "token_factory")]
+use crate::custom_query::{is_smart_token, smart_token_transfer_msg};
+use crate::error::ContractError;
+use crate::price_feed::query_price;
+use crate::queries::query_token_balance;
+use crate::routes::{next_hop, resolve_path};
+use crate::state::{Config, PendingCollect, BRIDGES, CONFIG, PENDING_COLLECT};
+
+type DepsMut<'a> = cosmwasm_std::DepsMut<'a, CustomQueryType>;
+
+/// Caps how many hops `SwapBridgeAssets` will walk in a single `Collect` call.
+pub const DEFAULT_BRIDGE_DEPTH: u64 = 2;
+
+/// ## Description
+/// Swaps every requested fee token into its configured bridge asset (or, if none
+/// is configured, into the first hop of an automatically discovered route to the
+/// stablecoin), then continues bridging and finally
+/// distributes the accumulated stablecoin to the configured targets.
+pub fn collect(
+    deps: DepsMut<'_>,
+    env: Env,
+    assets: Vec<AssetWithLimit>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let stablecoin_balance_before =
+        query_token_balance(&deps.querier, &config.stablecoin, &env.contract.address)?;
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    let mut bridge_assets: Vec<AssetInfo> = vec![];
+    let mut expected_output = Uint128::zero();
+    let mut price_cache: HashMap<String, Decimal> = HashMap::new();
+
+    for AssetWithLimit {
+        info,
+        limit,
+    } in assets
+    {
+        if info == config.stablecoin {
+            // already in the target asset, nothing to swap
+            continue;
+        }
+
+        let balance = query_token_balance(&deps.querier, &info, &env.contract.address)?;
+        let amount = match limit {
+            Some(limit) if limit < balance => limit,
+            _ => balance,
+        };
+
+        if amount.is_zero() {
+            continue;
+        }
+
+        let path = resolve_path(deps.as_ref(), &config, &info)?;
+        let hop_expected =
+            expected_path_output(deps.as_ref(), &config.factory_contract, &path, amount)?;
+
+        if let Some(price_feed) = &config.price_feed {
+            let denom = info.to_string();
+            let price = match price_cache.get(&denom) {
+                Some(price) => *price,
+                None => {
+                    let price = query_price(deps.as_ref(), price_feed, &denom)?;
+                    price_cache.insert(denom, price);
+                    price
+                },
+            };
+
+            let reference_output = amount * price;
+            let reference_minimum = reference_output * (Decimal::one() - config.max_spread);
+
+            if hop_expected < reference_minimum {
+                return Err(ContractError::OracleRejected {
+                    amm_output: hop_expected,
+                    reference_minimum,
+                });
+            }
+        }
+
+        expected_output += hop_expected;
+        let next_hop_info = path[1].clone();
+
+        let pair = query_pair(deps.as_ref(), &config.factory_contract, &info, &next_hop_info)?;
+        let offer_asset = Asset {
+            info,
+            amount,
+        };
+        messages.push(swap_msg(&pair.contract_addr, offer_asset, config.max_spread)?);
+
+        if next_hop_info != config.stablecoin && !bridge_assets.contains(&next_hop_info) {
+            bridge_assets.push(next_hop_info);
+        }
+    }
+
+    PENDING_COLLECT.save(
+        deps.storage,
+        &PendingCollect {
+            stablecoin_balance_before,
+            expected_output,
+        },
+    )?;
+
+    if !bridge_assets.is_empty() {
+        messages.push(
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: env.contract.address.to_string(),
+                msg: to_binary(&ExecuteMsg::SwapBridgeAssets {
+                    assets: bridge_assets,
+                    depth: DEFAULT_BRIDGE_DEPTH,
+                })?,
+                funds: vec![],
+            }),
+        );
+    }
+
+    messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_binary(&ExecuteMsg::DistributeFees {})?,
+        funds: vec![],
+    }));
+
+    Ok(Response::new().add_messages(messages).add_attribute("action", "collect"))
+}
+
+/// ## Description
+/// Continues bridging `assets` towards the stablecoin by one more hop each,
+/// recursing (via a self-call) until the stablecoin is reached or `depth` runs out.
+pub fn swap_bridge_assets(
+    deps: DepsMut<'_>,
+    env: Env,
+    assets: Vec<AssetInfo>,
+    depth: u64,
+) -> Result<Response, ContractError> {
+    if assets.is_empty() {
+        return Ok(Response::new().add_attribute("action", "swap_bridge_assets"));
+    }
+
+    if depth == 0 {
+        return Err(ContractError::MaxBridgeDepth(DEFAULT_BRIDGE_DEPTH));
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    let mut next_bridge_assets: Vec<AssetInfo> = vec![];
+
+    for info in assets {
+        let balance = query_token_balance(&deps.querier, &info, &env.contract.address)?;
+        if balance.is_zero() {
+            continue;
+        }
+
+        let next_hop_info = next_hop(deps.as_ref(), &config, &info)?;
+
+        let pair = query_pair(deps.as_ref(), &config.factory_contract, &info, &next_hop_info)?;
+        let offer_asset = Asset {
+            info,
+            amount: balance,
+        };
+        messages.push(swap_msg(&pair.contract_addr, offer_asset, config.max_spread)?);
+
+        if next_hop_info != config.stablecoin && !next_bridge_assets.contains(&next_hop_info) {
+            next_bridge_assets.push(next_hop_info);
+        }
+    }
+
+    if !next_bridge_assets.is_empty() {
+        messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            msg: to_binary(&ExecuteMsg::SwapBridgeAssets {
+                assets: next_bridge_assets,
+                depth: depth - 1,
+            })?,
+            funds: vec![],
+        }));
+    }
+
+    Ok(Response::new().add_messages(messages).add_attribute("action", "swap_bridge_assets"))
+}
+
+/// ## Description
+/// Distributes the contract's current stablecoin balance across `target_list` by weight.
+pub fn distribute_fees(deps: DepsMut<'_>, env: Env) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let balance =
+        query_token_balance(&deps.querier, &config.stablecoin, &env.contract.address)?;
+
+    if let Some(pending) = PENDING_COLLECT.may_load(deps.storage)? {
+        PENDING_COLLECT.remove(deps.storage);
+
+        let actual_received = balance.saturating_sub(pending.stablecoin_balance_before);
+        let minimum_received = pending.expected_output * (Decimal::one() - config.max_spread);
+
+        if actual_received < minimum_received {
+            return Err(ContractError::MaxSpreadAssertion {
+                actual: actual_received,
+                minimum_received,
+            });
+        }
+    }
+
+    if balance.is_zero() {
+        return Ok(Response::new().add_attribute("action", "distribute_fees"));
+    }
+
+    let total_weight: u64 = config.target_list.iter().map(|t| t.weight).sum();
+    if total_weight == 0 {
+        return Ok(Response::new().add_attribute("action", "distribute_fees"));
+    }
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    for target in config.target_list {
+        let amount = balance.multiply_ratio(target.weight, total_weight);
+        if amount.is_zero() {
+            continue;
+        }
+
+        let asset = Asset {
+            info: config.stablecoin.clone(),
+            amount,
+        };
+
+        messages.push(match target.msg {
+            Some(msg) => asset.send_msg(&target.addr, msg)?,
+            None => transfer_asset_msg(&env, &asset, &target.addr)?,
+        });
+    }
+
+    Ok(Response::new().add_messages(messages).add_attribute("action", "distribute_fees"))
+}
+
+/// ## Description
+/// Transfers `asset` to `to`, routing token-factory-issued denoms through the
+/// chain's custom send message instead of `BankMsg::Send` when the
+/// `token_factory` feature is enabled.
+fn transfer_asset_msg(env: &Env, asset: &Asset, to: &cosmwasm_std::Addr) -> Result<CosmosMsg, ContractError> {
+    #[cfg(feature = "token_factory")]
+    if let AssetInfo::NativeToken {
+        denom,
+    } = &asset.info
+    {
+        if is_smart_token(denom) {
+            return Ok(smart_token_transfer_msg(&env.contract.address, denom, asset.amount, to)?);
+        }
+    }
+
+    let _ = env;
+    Ok(asset.transfer_msg(to)?)
+}
+
+/// ## Description
+/// Adds or removes bridge asset declarations.
+pub fn update_bridges(
+    deps: DepsMut<'_>,
+    add: Option<Vec<(AssetInfo, AssetInfo)>>,
+    remove: Option<Vec<AssetInfo>>,
+) -> Result<Response, ContractError> {
+    if let Some(to_add) = add {
+        for (from, to) in to_add {
+            BRIDGES.save(deps.storage, from.to_string(), &(from.clone(), to))?;
+        }
+    }
+
+    if let Some(to_remove) = remove {
+        for from in to_remove {
+            BRIDGES.remove(deps.storage, from.to_string());
+        }
+    }
+
+    Ok(Response::new().add_attribute("action", "update_bridges"))
+}
+
+/// ## Description
+/// Updates the contract config.
+pub fn update_config(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    operator: Option<String>,
+    factory_contract: Option<String>,
+    target_list: Option<Vec<TargetConfigUnchecked>>,
+    max_spread: Option<cosmwasm_std::Decimal>,
+    price_feed: Option<String>,
+) -> Result<Response, ContractError> {
+    let mut config: Config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if let Some(operator) = operator {
+        config.operator = deps.api.addr_validate(&operator)?;
+    }
+
+    if let Some(factory_contract) = factory_contract {
+        config.factory_contract = deps.api.addr_validate(&factory_contract)?;
+    }
+
+    if let Some(target_list) = target_list {
+        config.target_list =
+            target_list.iter().map(|t| t.check(deps.api)).collect::<cosmwasm_std::StdResult<_>>()?;
+    }
+
+    if let Some(max_spread) = max_spread {
+        config.max_spread = max_spread;
+    }
+
+    if let Some(price_feed) = price_feed {
+        config.price_feed = if price_feed.is_empty() {
+            None
+        } else {
+            Some(deps.api.addr_validate(&price_feed)?)
+        };
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attribute("action", "update_config"))
+}
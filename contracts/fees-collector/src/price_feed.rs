@@ -0,0 +1,34 @@
+use cosmwasm_std::{Addr, Decimal, StdResult};
+use serde::{Deserialize, Serialize};
+
+use crate::custom_query::CustomQueryType;
+
+type Deps<'a> = cosmwasm_std::Deps<'a, CustomQueryType>;
+
+/// Query interface expected of a configured price-feed contract: given a fee
+/// token's identifier (its `AssetInfo::to_string()`), it returns that token's
+/// price denominated in the maker's stablecoin.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PriceFeedQueryMsg {
+    Price {
+        denom: String,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PriceResponse {
+    pub price: Decimal,
+}
+
+/// Queries `price_feed` for the stablecoin price of `denom`.
+pub fn query_price(deps: Deps<'_>, price_feed: &Addr, denom: &str) -> StdResult<Decimal> {
+    let response: PriceResponse = deps.querier.query_wasm_smart(
+        price_feed,
+        &PriceFeedQueryMsg::Price {
+            denom: denom.to_string(),
+        },
+    )?;
+
+    Ok(response.price)
+}
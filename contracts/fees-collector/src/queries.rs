@@ -0,0 +1,150 @@
+use astroport::asset::{Asset, AssetInfo};
+use cosmwasm_std::{Addr, Env, QuerierWrapper, StdResult, Uint128};
+use cw20::{BalanceResponse, Cw20QueryMsg};
+
+use eris::fees_collector::{
+    AssetWithLimit, BalancesResponse, BridgesResponse, ConfigResponse, RouteResponse,
+    SimulateCollectResponse,
+};
+
+use crate::custom_query::CustomQueryType;
+#[cfg(feature = "token_factory")]
+use crate::custom_query::{is_smart_token, query_smart_token_balance};
+use crate::state::{BRIDGES, CONFIG};
+
+type Deps<'a> = cosmwasm_std::Deps<'a, CustomQueryType>;
+
+/// Resolves the balance of `info` held by `address`, transparently routing
+/// token-factory-issued ("smart token") denoms through the custom chain query
+/// when the `token_factory` feature is enabled.
+pub fn query_token_balance(
+    querier: &QuerierWrapper<CustomQueryType>,
+    info: &AssetInfo,
+    address: &Addr,
+) -> StdResult<Uint128> {
+    match info {
+        AssetInfo::NativeToken {
+            denom,
+        } => {
+            #[cfg(feature = "token_factory")]
+            if is_smart_token(denom) {
+                return query_smart_token_balance(querier, denom, address);
+            }
+
+            Ok(querier.query_balance(address, denom)?.amount)
+        },
+        AssetInfo::Token {
+            contract_addr,
+        } => {
+            let response: BalanceResponse = querier.query_wasm_smart(
+                contract_addr,
+                &Cw20QueryMsg::Balance {
+                    address: address.to_string(),
+                },
+            )?;
+            Ok(response.balance)
+        },
+    }
+}
+
+/// Returns information about the maker config.
+pub fn query_config(deps: Deps<'_>) -> StdResult<ConfigResponse> {
+    let config = CONFIG.load(deps.storage)?;
+
+    Ok(ConfigResponse {
+        owner: config.owner.to_string(),
+        operator: config.operator.to_string(),
+        factory_contract: config.factory_contract.to_string(),
+        stablecoin: config.stablecoin,
+        target_list: config.target_list,
+        max_spread: config.max_spread,
+        price_feed: config.price_feed.map(|addr| addr.to_string()),
+    })
+}
+
+/// Returns the balance of each requested asset held by the contract.
+pub fn query_balances(
+    deps: Deps<'_>,
+    contract_addr: &Addr,
+    assets: Vec<AssetInfo>,
+) -> StdResult<BalancesResponse> {
+    let balances = assets
+        .into_iter()
+        .map(|info| {
+            let amount = query_token_balance(&deps.querier, &info, contract_addr)?;
+            Ok(Asset {
+                info,
+                amount,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(BalancesResponse {
+        balances,
+    })
+}
+
+/// Returns the declared bridge asset for every fee token that has one.
+pub fn query_bridges(deps: Deps<'_>) -> StdResult<BridgesResponse> {
+    let bridges = BRIDGES
+        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .map(|item| {
+            let (key, (_from, to)) = item?;
+            Ok((key, to))
+        })
+        .collect::<StdResult<Vec<(String, AssetInfo)>>>()?;
+
+    Ok(BridgesResponse {
+        bridges,
+    })
+}
+
+/// Returns the automatically-discovered swap route from `from` to the stablecoin.
+pub fn query_route(deps: Deps<'_>, from: AssetInfo) -> Result<RouteResponse, crate::error::ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let route = crate::routes::find_route(deps, &config, &from, crate::routes::DEFAULT_ROUTE_DEPTH)?;
+
+    Ok(RouteResponse {
+        route,
+    })
+}
+
+/// Previews the expected stablecoin output of a `Collect` call with `assets`,
+/// simulated the same way `Collect`/`DistributeFees` enforce it, but without
+/// swapping anything.
+pub fn query_simulate_collect(
+    deps: Deps<'_>,
+    env: &Env,
+    assets: Vec<AssetWithLimit>,
+) -> Result<SimulateCollectResponse, crate::error::ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let mut expected_output = Uint128::zero();
+    for AssetWithLimit {
+        info,
+        limit,
+    } in assets
+    {
+        if info == config.stablecoin {
+            continue;
+        }
+
+        let balance = query_token_balance(&deps.querier, &info, &env.contract.address)?;
+        let amount = match limit {
+            Some(limit) if limit < balance => limit,
+            _ => balance,
+        };
+
+        if amount.is_zero() {
+            continue;
+        }
+
+        let path = crate::routes::resolve_path(deps, &config, &info)?;
+        expected_output +=
+            crate::bridge::expected_path_output(deps, &config.factory_contract, &path, amount)?;
+    }
+
+    Ok(SimulateCollectResponse {
+        expected_output,
+    })
+}
@@ -0,0 +1,181 @@
+use std::collections::{HashSet, VecDeque};
+
+use astroport::asset::AssetInfo;
+use astroport::factory::{PairsResponse, QueryMsg as FactoryQueryMsg};
+use cosmwasm_std::Order;
+
+use crate::bridge::query_pair;
+use crate::custom_query::CustomQueryType;
+use crate::error::ContractError;
+use crate::state::{Config, BRIDGES};
+
+type Deps<'a> = cosmwasm_std::Deps<'a, CustomQueryType>;
+
+/// Returns the configured next hop for `from` if one was declared via `UpdateBridges`.
+pub fn declared_next_hop(deps: Deps<'_>, from: &AssetInfo) -> Result<Option<AssetInfo>, ContractError> {
+    Ok(BRIDGES.may_load(deps.storage, from.to_string())?.map(|(_, to)| to))
+}
+
+/// Returns the next hop to swap `from` into: the operator-declared bridge if one
+/// exists, otherwise the first hop of an automatically discovered route.
+pub fn next_hop(deps: Deps<'_>, config: &Config, from: &AssetInfo) -> Result<AssetInfo, ContractError> {
+    if let Some(to) = declared_next_hop(deps, from)? {
+        return Ok(to);
+    }
+
+    let route = find_route(deps, config, from, DEFAULT_ROUTE_DEPTH)?;
+    // route[0] == from, route[1] is the first hop towards the stablecoin
+    Ok(route[1].clone())
+}
+
+/// Resolves the full hop sequence from `from` to the stablecoin by repeatedly
+/// following [`next_hop`], bounded by `DEFAULT_ROUTE_DEPTH`. Unlike [`next_hop`],
+/// which only returns the immediate next step, this walks all the way to the
+/// stablecoin so the whole chain's expected output can be simulated up front.
+pub fn resolve_path(
+    deps: Deps<'_>,
+    config: &Config,
+    from: &AssetInfo,
+) -> Result<Vec<AssetInfo>, ContractError> {
+    let mut path = vec![from.clone()];
+
+    for _ in 0..DEFAULT_ROUTE_DEPTH {
+        let current = path.last().expect("path is never empty");
+        if current == &config.stablecoin {
+            return Ok(path);
+        }
+
+        let next = next_hop(deps, config, current)?;
+        path.push(next);
+    }
+
+    if path.last() == Some(&config.stablecoin) {
+        Ok(path)
+    } else {
+        Err(ContractError::NoRouteFound(from.to_string()))
+    }
+}
+
+/// Caps how many hops automatic route discovery will search.
+pub const DEFAULT_ROUTE_DEPTH: u64 = 3;
+
+/// Finds the shortest swap path from `from` to `config.stablecoin`, bounded by
+/// `depth` hops, via breadth-first search over a graph whose edges are (a) the
+/// operator-declared bridge pairs and (b) direct factory pairs between assets
+/// that appear anywhere in the factory's own pair list. Fails loudly (rather
+/// than returning a partial path) when no path reaches the stablecoin within `depth`.
+pub fn find_route(
+    deps: Deps<'_>,
+    config: &Config,
+    from: &AssetInfo,
+    depth: u64,
+) -> Result<Vec<AssetInfo>, ContractError> {
+    if from == &config.stablecoin {
+        return Ok(vec![from.clone()]);
+    }
+
+    let mut candidates = graph_assets(deps, config)?;
+    if !candidates.iter().any(|a| a == &config.stablecoin) {
+        candidates.push(config.stablecoin.clone());
+    }
+
+    let mut visited: HashSet<String> = HashSet::new();
+    visited.insert(from.to_string());
+
+    let mut queue: VecDeque<Vec<AssetInfo>> = VecDeque::new();
+    queue.push_back(vec![from.clone()]);
+
+    while let Some(path) = queue.pop_front() {
+        if path.len() as u64 > depth {
+            continue;
+        }
+
+        let current = path.last().expect("path is never empty");
+
+        for next in &candidates {
+            if visited.contains(&next.to_string()) {
+                continue;
+            }
+
+            if !is_edge(deps, config, current, next)? {
+                continue;
+            }
+
+            let mut next_path = path.clone();
+            next_path.push(next.clone());
+
+            if next == &config.stablecoin {
+                return Ok(next_path);
+            }
+
+            visited.insert(next.to_string());
+            queue.push_back(next_path);
+        }
+    }
+
+    Err(ContractError::NoRouteFound(from.to_string()))
+}
+
+/// Caps how many pages of `Factory::Pairs {}` route discovery will walk before
+/// giving up on enumerating the full pair graph.
+const MAX_FACTORY_PAIR_PAGES: u32 = 10;
+const FACTORY_PAIR_PAGE_LIMIT: u32 = 30;
+
+/// Every asset that appears as either side of a declared bridge pair, or as
+/// either side of a pair actually registered on the factory, used as the
+/// candidate set for automatic route discovery.
+fn graph_assets(deps: Deps<'_>, config: &Config) -> Result<Vec<AssetInfo>, ContractError> {
+    let mut assets: Vec<AssetInfo> = vec![];
+    let mut push_unique = |assets: &mut Vec<AssetInfo>, asset: AssetInfo| {
+        if !assets.iter().any(|a| a == &asset) {
+            assets.push(asset);
+        }
+    };
+
+    for item in BRIDGES.range(deps.storage, None, None, Order::Ascending) {
+        let (_, (from, to)) = item?;
+        push_unique(&mut assets, from);
+        push_unique(&mut assets, to);
+    }
+
+    let mut start_after: Option<[AssetInfo; 2]> = None;
+    for _ in 0..MAX_FACTORY_PAIR_PAGES {
+        let response: PairsResponse = deps.querier.query_wasm_smart(
+            config.factory_contract.clone(),
+            &FactoryQueryMsg::Pairs {
+                start_after: start_after.clone(),
+                limit: Some(FACTORY_PAIR_PAGE_LIMIT),
+            },
+        )?;
+
+        let page_len = response.pairs.len() as u32;
+        for pair in &response.pairs {
+            push_unique(&mut assets, pair.asset_infos[0].clone());
+            push_unique(&mut assets, pair.asset_infos[1].clone());
+        }
+
+        match response.pairs.last() {
+            Some(last) if page_len == FACTORY_PAIR_PAGE_LIMIT => {
+                start_after = Some([last.asset_infos[0].clone(), last.asset_infos[1].clone()]);
+            },
+            _ => break,
+        }
+    }
+
+    Ok(assets)
+}
+
+/// Whether `from -> to` is a usable edge: either declared via `UpdateBridges`,
+/// or directly tradeable as a pair on the factory.
+fn is_edge(
+    deps: Deps<'_>,
+    config: &Config,
+    from: &AssetInfo,
+    to: &AssetInfo,
+) -> Result<bool, ContractError> {
+    if declared_next_hop(deps, from)?.as_ref() == Some(to) {
+        return Ok(true);
+    }
+
+    Ok(query_pair(deps, &config.factory_contract, from, to).is_ok())
+}
@@ -0,0 +1,55 @@
+use astroport::asset::AssetInfo;
+use astroport::common::OwnershipProposal;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Uint128};
+use cw_storage_plus::{Item, Map};
+
+use eris::fees_collector::TargetConfigChecked;
+
+/// This structure stores general parameters for the contract.
+#[cw_serde]
+pub struct Config {
+    /// Address that's allowed to update config
+    pub owner: Addr,
+    /// Address that's allowed to update bridge assets
+    pub operator: Addr,
+    /// The factory contract address
+    pub factory_contract: Addr,
+    /// The stablecoin asset info
+    pub stablecoin: AssetInfo,
+    /// The beneficiary addresses to received fees in stablecoin
+    pub target_list: Vec<TargetConfigChecked>,
+    /// The maximum spread used when swapping fee tokens
+    pub max_spread: Decimal,
+    /// An optional price-feed contract used as an additional slippage guard: when
+    /// set, `Collect` rejects any swap whose AMM-simulated output falls more than
+    /// `max_spread` below the output implied by the feed's price, independent of
+    /// the pool's own reserves
+    pub price_feed: Option<Addr>,
+}
+
+/// Stores the config at the given key.
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// Stores bridge tokens used to swap fee tokens to the stablecoin, keyed by the
+/// (string-serialized) `AssetInfo` of the fee token, valued by the `(from, to)` pair
+/// so both ends of the edge can be recovered when building the route graph.
+pub const BRIDGES: Map<String, (AssetInfo, AssetInfo)> = Map::new("bridges");
+
+/// Contains a proposal to change contract ownership.
+pub const OWNERSHIP_PROPOSAL: Item<OwnershipProposal> = Item::new("ownership_proposal");
+
+/// The simulated outcome of the `Collect` call in flight, set just before its
+/// swap messages are dispatched and consumed by the `DistributeFees` callback
+/// at the end of the same call chain to enforce the overall spread.
+#[cw_serde]
+pub struct PendingCollect {
+    /// The contract's stablecoin balance right before the collect's swaps were issued
+    pub stablecoin_balance_before: Uint128,
+    /// The expected stablecoin output of the collect's swaps, simulated via the
+    /// constant-product formula against each hop's own reserves
+    pub expected_output: Uint128,
+}
+
+/// Stores the in-flight `Collect` call's expected outcome, if any.
+pub const PENDING_COLLECT: Item<PendingCollect> = Item::new("pending_collect");
@@ -0,0 +1,123 @@
+use astroport::asset::{Asset, AssetInfo};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{to_binary, Addr, CosmosMsg, Decimal, StdResult, Uint128, WasmMsg};
+use cw20::Cw20ReceiveMsg;
+
+/// This structure stores general parameters for the contract.
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// Address that's allowed to update config
+    pub owner: String,
+    /// The LP token that can be bonded
+    pub lp_token: String,
+    /// The compound proxy contract used to turn rewards back into `lp_token`
+    pub compound_proxy: String,
+    /// The generator/staking contract that `lp_token` is deposited into
+    pub staking_contract: String,
+    /// The asset that the staking contract pays out as a reward
+    pub base_reward_token: AssetInfo,
+    /// Code id used to instantiate the amp LP (staking derivative) token
+    pub token_code_id: u64,
+}
+
+/// This structure describes the functions that can be executed in this contract.
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Deposits assets, creates the underlying LP token via the compound proxy and bonds it
+    BondAssets {
+        assets: Vec<Asset>,
+        minimum_receive: Option<Uint128>,
+        no_swap: Option<bool>,
+        slippage_tolerance: Option<Decimal>,
+    },
+    /// Receives a cw20 send: bonds a received LP token, or unbonds a received amp LP token,
+    /// on behalf of the sender
+    Receive(Cw20ReceiveMsg),
+    /// Withdraws a bonder's accrued pending reward
+    Withdraw {},
+    /// Updates contract config
+    UpdateConfig {
+        compound_proxy: Option<String>,
+    },
+    /// Creates a request to change the contract's ownership
+    ProposeNewOwner {
+        owner: String,
+        expires_in: u64,
+    },
+    /// Removes a request to change contract ownership
+    DropOwnershipProposal {},
+    /// Claims contract ownership
+    ClaimOwnership {},
+    /// Internal callbacks only callable by the contract itself
+    Callback(CallbackMsg),
+}
+
+/// This structure describes the callbacks available in the contract.
+#[cw_serde]
+pub enum CallbackMsg {
+    /// Bonds the LP token received from the compound proxy on behalf of `to`
+    BondTo {
+        to: Addr,
+        prev_balance: Uint128,
+        minimum_receive: Option<Uint128>,
+    },
+}
+
+impl CallbackMsg {
+    pub fn into_cosmos_msg(&self, contract_addr: &Addr) -> StdResult<CosmosMsg> {
+        Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: contract_addr.to_string(),
+            msg: to_binary(&ExecuteMsg::Callback(self.clone()))?,
+            funds: vec![],
+        }))
+    }
+}
+
+/// This structure describes a cw20 receive hook message.
+#[cw_serde]
+pub enum Cw20HookMsg {
+    /// Bonds the received LP token on behalf of the sender
+    Bond {},
+    /// Burns the received amp LP token and unbonds the underlying LP token
+    Unbond {},
+}
+
+/// This structure describes the query functions available in the contract.
+#[cw_serde]
+pub enum QueryMsg {
+    /// Returns information about the farm config
+    Config {},
+    /// Returns the current bond state
+    State {},
+    /// Returns a staker's currently claimable pending reward
+    Reward {
+        address: String,
+    },
+}
+
+/// This structure describes a migration message.
+/// We currently take no arguments for migrations.
+#[cw_serde]
+pub struct MigrateMsg {}
+
+/// Response for [`QueryMsg::Config`].
+#[cw_serde]
+pub struct ConfigResponse {
+    pub owner: String,
+    pub lp_token: String,
+    pub compound_proxy: String,
+    pub staking_contract: String,
+}
+
+/// Response for [`QueryMsg::State`].
+#[cw_serde]
+pub struct StateResponse {
+    pub total_bond_share: Uint128,
+    pub total_lp_amount: Uint128,
+}
+
+/// Response for [`QueryMsg::Reward`].
+#[cw_serde]
+pub struct RewardResponse {
+    pub pending: Uint128,
+}
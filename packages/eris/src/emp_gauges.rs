@@ -0,0 +1,88 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint128};
+
+/// This structure stores general parameters for the contract.
+#[cw_serde]
+pub struct ConfigResponse {
+    /// Address that's allowed to update config and tune emissions
+    pub owner: Addr,
+    /// Caps how many validators a single [`ExecuteMsg::TuneEmps`] call will write weights for
+    pub validators_limit: u64,
+}
+
+/// This structure stores a validator's decaying voting power, checkpointed at a specific period.
+#[cw_serde]
+#[derive(Default)]
+pub struct VotedValidatorInfoResponse {
+    /// The decaying voting power (`bias`) as of the checkpoint period
+    pub voting_power: Uint128,
+    /// The rate at which `voting_power` decays per elapsed period
+    pub slope: Uint128,
+}
+
+/// This structure stores the emission weight last computed for every validator during tuning.
+#[cw_serde]
+pub struct GaugeInfoResponse {
+    /// The period the weights were computed for
+    pub tune_period: u64,
+    /// Validator address and its tuned emission weight, combining decayed votes and fixed emps
+    pub validators: Vec<(String, Uint128)>,
+}
+
+/// This structure stores general parameters for the contract.
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// Address that's allowed to update config and tune emissions
+    pub owner: String,
+    /// Caps how many validators a single [`ExecuteMsg::TuneEmps`] call will write weights for
+    pub validators_limit: u64,
+}
+
+/// This structure describes the functions that can be executed in this contract.
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Casts a vote for `validator`, locking `amount` of voting power until `lock_end_period`
+    Vote {
+        validator: String,
+        amount: Uint128,
+        lock_end_period: u64,
+    },
+    /// Sets a validator's non-decaying fixed emp weight from the current period onward
+    UpdateFixedEmps {
+        validator: String,
+        amount: Uint128,
+    },
+    /// Recomputes every validator's tuned emission weight up to the current period
+    TuneEmps {},
+    /// Updates contract config
+    UpdateConfig {
+        validators_limit: Option<u64>,
+    },
+    /// Creates a request to change the contract's ownership
+    ProposeNewOwner {
+        owner: String,
+        expires_in: u64,
+    },
+    /// Removes a request to change contract ownership
+    DropOwnershipProposal {},
+    /// Claims contract ownership
+    ClaimOwnership {},
+}
+
+/// This structure describes the query functions available in the contract.
+#[cw_serde]
+pub enum QueryMsg {
+    /// Returns information about the contract config
+    Config {},
+    /// Returns a validator's voting power decayed to the current period
+    ValidatorInfo {
+        validator: String,
+    },
+    /// Returns the last tuning result
+    TuneInfo {},
+}
+
+/// This structure describes a migration message.
+/// We currently take no arguments for migrations.
+#[cw_serde]
+pub struct MigrateMsg {}
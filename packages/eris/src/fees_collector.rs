@@ -18,6 +18,8 @@ pub struct InstantiateMsg {
     pub target_list: Vec<TargetConfigUnchecked>,
     /// The maximum spread used when swapping fee tokens
     pub max_spread: Option<Decimal>,
+    /// An optional price-feed contract used as an additional slippage guard on `Collect`
+    pub price_feed: Option<String>,
 }
 
 /// This structure describes the functions that can be executed in this contract.
@@ -39,6 +41,9 @@ pub enum ExecuteMsg {
         target_list: Option<Vec<TargetConfigUnchecked>>,
         /// The maximum spread used when swapping fee tokens
         max_spread: Option<Decimal>,
+        /// An optional price-feed contract used as an additional slippage guard on `Collect`.
+        /// Pass `Some("")` to clear a previously configured feed.
+        price_feed: Option<String>,
     },
     /// Add bridge tokens used to swap specific fee tokens to stablecoin (effectively declaring a swap route)
     UpdateBridges {
@@ -79,6 +84,34 @@ pub enum QueryMsg {
     },
     /// Returns list of bridge assets
     Bridges {},
+    /// Returns the automatically-discovered swap route from `from` to the stablecoin
+    Route {
+        from: AssetInfo,
+    },
+    /// Previews the expected stablecoin output of a `Collect` call with the given
+    /// assets, simulated via the constant-product formula without swapping anything
+    SimulateCollect {
+        assets: Vec<AssetWithLimit>,
+    },
+}
+
+/// A custom struct used to return contract config.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ConfigResponse {
+    /// Address that's allowed to update config
+    pub owner: String,
+    /// Address that's allowed to update bridge assets
+    pub operator: String,
+    /// The factory contract address
+    pub factory_contract: String,
+    /// The stablecoin asset info
+    pub stablecoin: AssetInfo,
+    /// The beneficiary addresses to received fees in stablecoin
+    pub target_list: Vec<TargetConfigChecked>,
+    /// The maximum spread used when swapping fee tokens
+    pub max_spread: Decimal,
+    /// The configured price-feed contract, if any
+    pub price_feed: Option<String>,
 }
 
 /// A custom struct used to return multiple asset balances.
@@ -88,6 +121,27 @@ pub struct BalancesResponse {
     pub balances: Vec<Asset>,
 }
 
+/// A custom struct used to return the declared bridge asset for every fee token that has one.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BridgesResponse {
+    /// List of (fee token, bridge asset) pairs
+    pub bridges: Vec<(String, AssetInfo)>,
+}
+
+/// A custom struct used to return a discovered swap route.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RouteResponse {
+    /// The discovered hop sequence, starting with the requested asset and ending with the stablecoin
+    pub route: Vec<AssetInfo>,
+}
+
+/// A custom struct used to return a `Collect` preview.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SimulateCollectResponse {
+    /// The expected stablecoin output, simulated via the constant-product formula
+    pub expected_output: Uint128,
+}
+
 /// This structure describes a migration message.
 /// We currently take no arguments for migrations.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]